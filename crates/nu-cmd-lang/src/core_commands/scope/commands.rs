@@ -2,7 +2,8 @@ use nu_engine::scope::ScopeData;
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
-    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Type,
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature,
+    SyntaxShape, Type, Value,
 };
 
 #[derive(Clone)]
@@ -16,6 +17,12 @@ impl Command for ScopeCommands {
     fn signature(&self) -> Signature {
         Signature::build("scope commands")
             .input_output_types(vec![(Type::Nothing, Type::Any)])
+            .named(
+                "closest",
+                SyntaxShape::String,
+                "only list commands whose name is close to this string, nearest first",
+                None,
+            )
             .allow_variants_without_examples(true)
             .category(Category::Filters)
     }
@@ -37,18 +44,96 @@ impl Command for ScopeCommands {
         let mut scope_data = ScopeData::new(engine_state, stack);
         scope_data.populate_all();
 
-        Ok(scope_data.collect_commands(span).into_pipeline_data(ctrlc))
+        let commands = scope_data.collect_commands(span);
+
+        let commands = match call.get_flag::<String>(engine_state, stack, "closest")? {
+            Some(query) => closest_commands(commands, &query, span),
+            None => commands,
+        };
+
+        Ok(commands.into_pipeline_data(ctrlc))
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Show the commands in the current scope",
-            example: "scope commands",
-            result: None,
-        }]
+        vec![
+            Example {
+                description: "Show the commands in the current scope",
+                example: "scope commands",
+                result: None,
+            },
+            Example {
+                description: "Show the commands whose name is closest to 'lenght'",
+                example: "scope commands --closest lenght",
+                result: None,
+            },
+        ]
     }
 }
 
+/// Never return more than this many suggestions from `--closest`, no matter how many commands
+/// fall within the distance threshold, matching the handful of suggestions cargo prints for a
+/// mistyped subcommand instead of dumping every vaguely-similar name.
+const MAX_CLOSEST_MATCHES: usize = 5;
+
+/// Filter `commands` (the records produced by [`ScopeData::collect_commands`]) down to the
+/// ones whose `name` is closest to `query` by Levenshtein edit distance, the same heuristic
+/// cargo uses to suggest mistyped subcommands. Each kept record gains a `distance` column, and
+/// the result is sorted ascending by distance, then by name, and capped at
+/// [`MAX_CLOSEST_MATCHES`].
+fn closest_commands(commands: Vec<Value>, query: &str, span: nu_protocol::Span) -> Vec<Value> {
+    // Same rule of thumb cargo's `edit_distance` callers use: don't suggest something that's
+    // barely related to what was typed.
+    let threshold = query.chars().count() / 3 + 1;
+
+    let mut matches: Vec<(usize, String, Value)> = commands
+        .into_iter()
+        .filter_map(|mut record| {
+            let name = record.get_data_by_key("name")?.as_str().ok()?.to_string();
+            let distance = levenshtein_distance(&name, query);
+            if distance > threshold {
+                return None;
+            }
+            if let Value::Record { val, .. } = &mut record {
+                val.push("distance", Value::int(distance as i64, span));
+            }
+            Some((distance, name, record))
+        })
+        .collect();
+
+    matches.sort_by(|(dist_a, name_a, _), (dist_b, name_b, _)| {
+        dist_a.cmp(dist_b).then_with(|| name_a.cmp(name_b))
+    });
+    matches.truncate(MAX_CLOSEST_MATCHES);
+
+    matches.into_iter().map(|(_, _, record)| record).collect()
+}
+
+/// Standard Levenshtein edit distance between `a` and `b`.
+fn levenshtein_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    let mut d = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+
+    for (i, row) in d.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for j in 0..=b.len() {
+        d[0][j] = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            d[i][j] = (d[i - 1][j] + 1)
+                .min(d[i][j - 1] + 1)
+                .min(d[i - 1][j - 1] + cost);
+        }
+    }
+
+    d[a.len()][b.len()]
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -59,4 +144,30 @@ mod test {
 
         test_examples(ScopeCommands {})
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn levenshtein_distance_matches_known_values() {
+        assert_eq!(levenshtein_distance("", ""), 0);
+        assert_eq!(levenshtein_distance("abc", "abc"), 0);
+        assert_eq!(levenshtein_distance("kitten", "sitting"), 3);
+        assert_eq!(levenshtein_distance("length", "lenght"), 2);
+    }
+
+    #[test]
+    fn closest_commands_caps_results_even_when_more_are_within_threshold() {
+        use nu_protocol::{record, Span};
+
+        let commands: Vec<Value> = (0..MAX_CLOSEST_MATCHES + 2)
+            .map(|i| {
+                Value::record(
+                    record! { "name" => Value::test_string(format!("foo{i}")) },
+                    Span::test_data(),
+                )
+            })
+            .collect();
+
+        let result = closest_commands(commands, "foo", Span::test_data());
+
+        assert_eq!(result.len(), MAX_CLOSEST_MATCHES);
+    }
+}
@@ -1,13 +1,457 @@
+use std::collections::HashSet;
 use std::path::PathBuf;
+use std::sync::{Mutex, OnceLock};
 
 use nu_engine::{env::current_dir, eval_block};
 use nu_parser::parse;
 use nu_protocol::engine::{Stack, StateWorkingSet, VirtualPath};
-use nu_protocol::{report_error, PipelineData};
+use nu_protocol::{report_error, PipelineData, Value};
 
 // Virtual std directory unlikely to appear in user's file system
 const NU_STDLIB_VIRTUAL_DIR: &str = "NU_STDLIB_VIRTUAL_DIR";
 
+// Virtual root under which third-party libraries registered via `register_library` live,
+// kept distinct from `NU_STDLIB_VIRTUAL_DIR` so a library can't shadow `std` itself.
+const NU_THIRD_PARTY_VIRTUAL_DIR: &str = "NU_THIRD_PARTY_VIRTUAL_DIR";
+
+// Names of the submodules that make up `std`. Used only to word the warning for a
+// `std_prelude` entry whose `target` `find_decl` could not resolve (see `is_known_prelude_target`).
+const STD_MODULE_NAMES: &[&str] = &[
+    "log", "mod.nu", "assert.nu", "input.nu", "dirs", "iter", "help", "testing", "xml", "dt",
+    "i18n",
+];
+
+// Default locale used when `$env.LC_MESSAGES`/`$env.LANG` is unset or names a locale we don't
+// have a catalog for, mirroring the forge build tool's fallback to `"C"`.
+const DEFAULT_LOCALE: &str = "C";
+
+// Message catalogs for `std i18n`, keyed by locale tag. Add an entry here (and a matching
+// `lib/i18n/<tag>.nu` table) to ship a new translation; `DEFAULT_LOCALE` must always be present.
+const LOCALE_CATALOGS: &[(&str, &str)] = &[(DEFAULT_LOCALE, include_str!("../lib/i18n/C.nu"))];
+
+// Submodules that must be parsed and merged at startup because they register `export-env`
+// hooks (e.g. `dirs` sets up the directory stack) or are needed to define `std` itself.
+const EAGER_STD_MODULES: &[(&str, &str)] = &[
+    ("log", include_str!("../lib/log.nu")),
+    ("mod.nu", include_str!("../std/mod.nu")),
+    ("dirs", include_str!("../std/dirs.nu")),
+];
+
+// The rest of `std`. Parsed and merged up front by default, same as `EAGER_STD_MODULES`; set
+// `NU_STDLIB_LAZY=1` to defer them to the first time one of their commands is actually resolved
+// instead, provided the embedder's command resolver calls `lazy_stdlib_module_for_command`/
+// `load_lazy_stdlib_module` on a miss (see `stdlib_lazy_mode`).
+const LAZY_STD_MODULES: &[(&str, &str)] = &[
+    ("assert.nu", include_str!("../std/assert.nu")),
+    ("input.nu", include_str!("../std/input.nu")),
+    ("iter", include_str!("../lib/iter.nu")),
+    ("help", include_str!("../lib/help.nu")),
+    ("testing", include_str!("../lib/testing.nu")),
+    ("xml", include_str!("../lib/xml.nu")),
+    ("dt", include_str!("../lib/dt.nu")),
+];
+
+// Keyed by `engine_state_key`, the same way `registered_libraries` is: whether a lazy module
+// has been merged is a property of one `EngineState`, not the process, so a module loaded into
+// engine A must not make `load_lazy_stdlib_module` think it's already loaded for engine B.
+fn loaded_lazy_modules() -> &'static Mutex<std::collections::HashMap<usize, HashSet<String>>> {
+    static LOADED: OnceLock<Mutex<std::collections::HashMap<usize, HashSet<String>>>> =
+        OnceLock::new();
+    LOADED.get_or_init(|| Mutex::new(std::collections::HashMap::new()))
+}
+
+fn stdlib_eager_override() -> bool {
+    std::env::var("NU_STDLIB_EAGER").is_ok_and(|v| v != "0")
+}
+
+fn stdlib_lazy_requested() -> bool {
+    std::env::var("NU_STDLIB_LAZY").is_ok_and(|v| v != "0")
+}
+
+/// Whether `load_standard_library` should defer [`LAZY_STD_MODULES`] instead of loading
+/// everything up front.
+///
+/// This is opt-in (`NU_STDLIB_LAZY=1`), not the default: nothing in this crate hooks
+/// [`load_lazy_stdlib_module`] into command resolution (that lives in the parser/engine, not
+/// here), so turning laziness on without a resolver that calls
+/// [`lazy_stdlib_module_for_command`] on a cache miss would make most of `std` silently
+/// disappear. `NU_STDLIB_EAGER=1` always wins over `NU_STDLIB_LAZY=1`, for debugging.
+fn stdlib_lazy_mode() -> bool {
+    stdlib_lazy_requested() && !stdlib_eager_override()
+}
+
+/// Name of the std submodule (as it appears in [`LAZY_STD_MODULES`]) that would export a
+/// command named `command_name`, e.g. `"xml from html"` -> `"xml"`.
+///
+/// Callers resolving an unknown command should check this before giving up, and call
+/// [`load_lazy_stdlib_module`] with the result to pull that submodule in on demand.
+pub fn lazy_stdlib_module_for_command(command_name: &str) -> Option<&'static str> {
+    let head = command_name.split_whitespace().next()?;
+    LAZY_STD_MODULES
+        .iter()
+        .map(|(name, _)| name.trim_end_matches(".nu"))
+        .find(|name| *name == head)
+}
+
+/// Parse and merge a single deferred `std` submodule into `engine_state`, if it hasn't been
+/// already. Returns `true` if this call is what loaded it, `false` if it was already loaded
+/// (or isn't a known lazy module).
+pub fn load_lazy_stdlib_module(
+    engine_state: &mut nu_protocol::engine::EngineState,
+    module_name: &str,
+) -> Result<bool, miette::ErrReport> {
+    let Some((file_name, content)) = LAZY_STD_MODULES
+        .iter()
+        .find(|(name, _)| name.trim_end_matches(".nu") == module_name)
+    else {
+        return Ok(false);
+    };
+
+    let key = engine_state_key(engine_state);
+    if loaded_lazy_modules()
+        .lock()
+        .expect("lazy stdlib lock poisoned")
+        .get(&key)
+        .is_some_and(|loaded| loaded.contains(module_name))
+    {
+        return Ok(false);
+    }
+
+    let std_dir = PathBuf::from(NU_STDLIB_VIRTUAL_DIR).join("std");
+    let (block, delta) = {
+        let mut working_set = StateWorkingSet::new(engine_state);
+
+        let file_path = std_dir.join(file_name);
+        let file_id =
+            working_set.add_file(file_path.to_string_lossy().to_string(), content.as_bytes());
+        working_set.add_virtual_path(
+            file_path.to_string_lossy().to_string(),
+            VirtualPath::File(file_id),
+        );
+
+        let source = format!("module {} {{\n{content}\n}}\n", module_name);
+
+        let prev_currently_parsed_cwd = working_set.currently_parsed_cwd.clone();
+        working_set.currently_parsed_cwd = Some(PathBuf::from(NU_STDLIB_VIRTUAL_DIR));
+
+        let block = parse(
+            &mut working_set,
+            Some(&format!("loading stdlib module {module_name}")),
+            source.as_bytes(),
+            false,
+        );
+
+        if let Some(err) = working_set.parse_errors.first() {
+            report_error(&working_set, err);
+        }
+
+        working_set.currently_parsed_cwd = prev_currently_parsed_cwd;
+
+        (block, working_set.render())
+    };
+
+    engine_state.merge_delta(delta)?;
+
+    let mut stack = Stack::new();
+    eval_block(
+        engine_state,
+        &mut stack,
+        &block,
+        PipelineData::Empty,
+        false,
+        false,
+    )?;
+
+    let cwd = current_dir(engine_state, &stack)?;
+    engine_state.merge_env(&mut stack, cwd)?;
+
+    // Only mark the module loaded once it has actually been merged: if `merge_delta`/
+    // `eval_block` above had failed (returning `Err` before this point), a later retry should
+    // get a real second attempt instead of a permanent false `Ok(false)`. A parse error is only
+    // reported, not returned, so a module with parse errors is still marked loaded here.
+    loaded_lazy_modules()
+        .lock()
+        .expect("lazy stdlib lock poisoned")
+        .entry(key)
+        .or_default()
+        .insert(module_name.to_string());
+
+    Ok(true)
+}
+
+/// A single `{ alias: ..., target: ... }` entry from `$env.config.std_prelude`.
+struct PreludeEntry {
+    alias: String,
+    target: String,
+}
+
+/// Read `$env.config.std_prelude` (if present) and turn it into prelude entries, on top of
+/// the built-in ones, the same way cargo's `aliased_command` reads `alias.<name>` from config.
+///
+/// Malformed entries are skipped rather than aborting the whole load, since a typo in a
+/// user's config shouldn't keep the rest of `std` from coming up.
+fn config_prelude_entries(engine_state: &nu_protocol::engine::EngineState) -> Vec<PreludeEntry> {
+    let Some(config) = engine_state.get_env_var("config") else {
+        return vec![];
+    };
+
+    let Some(std_prelude) = config.get_data_by_key("std_prelude") else {
+        return vec![];
+    };
+
+    let Value::List { vals, .. } = std_prelude else {
+        return vec![];
+    };
+
+    vals.into_iter()
+        .filter_map(|entry| {
+            let record = entry.as_record().ok()?;
+            let alias = record.get("alias")?.as_str().ok()?.to_string();
+            let target = record.get("target")?.as_str().ok()?.to_string();
+            Some(PreludeEntry { alias, target })
+        })
+        .collect()
+}
+
+/// Does `target` (e.g. `"dirs"` or `"help commands"`) *name* a real `std` submodule, whether or
+/// not that submodule is actually loaded right now? Used only to word the warning in
+/// [`load_extra_prelude`] accurately once `find_decl` has already said the command isn't
+/// resolvable; [`load_extra_prelude`] is what decides whether an entry is usable.
+fn is_known_prelude_target(target: &str) -> bool {
+    let module = target.split_whitespace().next().unwrap_or(target);
+    STD_MODULE_NAMES
+        .iter()
+        .any(|name| name.trim_end_matches(".nu") == module)
+}
+
+type LibraryFiles = Vec<(&'static str, &'static str)>;
+
+/// A registered-but-not-yet-loaded third-party library: its files, plus the command names (as
+/// exported from its own top-level module, e.g. `"greet"` or `"greet hello"`) that should be
+/// spliced into the unprefixed namespace the same way `std`'s own prelude is, so they don't
+/// have to be reached through `use <name> ...` first.
+type PendingLibrary = (String, LibraryFiles, Vec<&'static str>);
+
+// Keyed by the registering `EngineState`'s address rather than a single flat `Vec`, so that
+// registering a library against one `EngineState` doesn't leak into, or get re-merged by, a
+// different `EngineState` in the same process (e.g. two independent engines in a test suite).
+// A key is removed as soon as [`load_registered_libraries`] drains it, so calling
+// `load_standard_library` again on the same `EngineState` doesn't re-merge libraries it already
+// loaded. This does mean a freed `EngineState`'s address being reused by a new one could, in
+// principle, inherit stale pending registrations; in practice `load_standard_library` runs
+// once per `EngineState` shortly after it's created, well before that could happen.
+fn registered_libraries() -> &'static Mutex<std::collections::HashMap<usize, Vec<PendingLibrary>>>
+{
+    static REGISTRY: OnceLock<Mutex<std::collections::HashMap<usize, Vec<PendingLibrary>>>> =
+        OnceLock::new();
+    REGISTRY.get_or_init(|| Mutex::new(std::collections::HashMap::new()))
+}
+
+fn engine_state_key(engine_state: &nu_protocol::engine::EngineState) -> usize {
+    engine_state as *const nu_protocol::engine::EngineState as usize
+}
+
+/// Lets crate embedders and users plug in their own always-available "std-like" libraries,
+/// the way forge's `Backend` trait lets third parties plug in their own DVCS backends.
+///
+/// This is a standalone trait rather than an inherent `EngineState` method because
+/// `EngineState` lives in `nu-protocol`, not here.
+pub trait RegisterLibraryExt {
+    /// Register `name` as a library made up of `files`, in the same `(file_name, source)`
+    /// shape `load_standard_library` uses for `std`'s own submodules, and `prelude` as the
+    /// commands from it that should be reachable unprefixed (mirroring `std`'s own prelude),
+    /// e.g. `register_library("greet", files, vec!["greet"])` makes `greet` callable directly
+    /// instead of only as `greet greet`. Only single-segment commands are supported in
+    /// `prelude`; a multi-word entry (e.g. `"greet hello"`) is dropped with a warning instead
+    /// of being spliced in wrong, since bracket-importing it would bring `greet` and `hello`
+    /// into scope as two unrelated commands rather than the `greet hello` subcommand. The
+    /// library is parsed and merged against *this* `EngineState` the next time
+    /// [`load_standard_library`] runs on it; a parse error in one registered library is
+    /// reported but doesn't prevent `std` or any other registered library from loading.
+    fn register_library(
+        &mut self,
+        name: &str,
+        files: Vec<(&'static str, &'static str)>,
+        prelude: Vec<&'static str>,
+    );
+}
+
+impl RegisterLibraryExt for nu_protocol::engine::EngineState {
+    fn register_library(
+        &mut self,
+        name: &str,
+        files: Vec<(&'static str, &'static str)>,
+        prelude: Vec<&'static str>,
+    ) {
+        let key = engine_state_key(self);
+        registered_libraries()
+            .lock()
+            .expect("third-party library registry lock poisoned")
+            .entry(key)
+            .or_default()
+            .push((name.to_string(), files, prelude));
+    }
+}
+
+/// Parse and merge one third-party library registered via [`RegisterLibraryExt::register_library`],
+/// evaluating its `export-env` blocks just like `std`'s, and splicing `prelude`'s commands into
+/// the unprefixed namespace. Reuses the same `add_file`/`add_virtual_path`/`VirtualPath::Dir`
+/// machinery as `load_standard_library`, under `NU_THIRD_PARTY_VIRTUAL_DIR` so it can't collide
+/// with `std` or the user's file system.
+fn load_third_party_library(
+    engine_state: &mut nu_protocol::engine::EngineState,
+    name: &str,
+    files: &[(&'static str, &'static str)],
+    prelude: &[&'static str],
+) -> Result<(), miette::ErrReport> {
+    let lib_dir = PathBuf::from(NU_THIRD_PARTY_VIRTUAL_DIR).join(name);
+
+    let (block, delta) = {
+        let mut working_set = StateWorkingSet::new(engine_state);
+        let mut virt_paths = vec![];
+
+        for (file_name, content) in files {
+            let file_path = lib_dir.join(file_name);
+            let file_id = working_set
+                .add_file(file_path.to_string_lossy().to_string(), content.as_bytes());
+            let virtual_file_id = working_set.add_virtual_path(
+                file_path.to_string_lossy().to_string(),
+                VirtualPath::File(file_id),
+            );
+            virt_paths.push(virtual_file_id);
+        }
+
+        let lib_dir_str = lib_dir.to_string_lossy().to_string();
+        let _ = working_set.add_virtual_path(lib_dir_str.clone(), VirtualPath::Dir(virt_paths));
+
+        // Only single-segment commands can be bracket-imported this way: `use {name} [a, b]`
+        // brings `a` and `b` into scope unprefixed as-is, but a multi-word entry like `greet
+        // hello` would be parsed as two separate bracket items (`greet` and `hello`), not the
+        // `greet hello` subcommand. Rather than guess at a rename, such entries are dropped
+        // with a warning; register_library callers should only list single-segment commands.
+        let (single_segment, multi_word): (Vec<&'static str>, Vec<&'static str>) = prelude
+            .iter()
+            .copied()
+            .partition(|command| command.split_whitespace().count() <= 1);
+
+        for command in &multi_word {
+            eprintln!(
+                "warning: ignoring prelude command `{command}` registered for third-party \
+                 library `{name}`: only single-segment commands can be added to the prelude"
+            );
+        }
+
+        let prelude_use = if single_segment.is_empty() {
+            String::new()
+        } else {
+            let commands = single_segment.join("\n    ");
+            format!("use {name} [\n    {commands}\n]\n")
+        };
+
+        let source = format!("module {lib_dir_str}\n{prelude_use}");
+
+        let prev_currently_parsed_cwd = working_set.currently_parsed_cwd.clone();
+        working_set.currently_parsed_cwd = Some(PathBuf::from(NU_THIRD_PARTY_VIRTUAL_DIR));
+
+        let block = parse(
+            &mut working_set,
+            Some(&format!("loading third-party library {name}")),
+            source.as_bytes(),
+            false,
+        );
+
+        if let Some(err) = working_set.parse_errors.first() {
+            report_error(&working_set, err);
+        }
+
+        working_set.currently_parsed_cwd = prev_currently_parsed_cwd;
+
+        (block, working_set.render())
+    };
+
+    engine_state.merge_delta(delta)?;
+
+    let mut stack = Stack::new();
+    eval_block(
+        engine_state,
+        &mut stack,
+        &block,
+        PipelineData::Empty,
+        false,
+        false,
+    )?;
+
+    let cwd = current_dir(engine_state, &stack)?;
+    engine_state.merge_env(&mut stack, cwd)?;
+
+    Ok(())
+}
+
+/// Load every library registered so far against this `EngineState` via
+/// [`RegisterLibraryExt::register_library`], then forget them: a second call (e.g. from a
+/// second `load_standard_library` on the same `EngineState`) won't re-merge them. A broken
+/// third-party library is reported to stderr and skipped rather than aborting the others or
+/// the built-in `std`.
+fn load_registered_libraries(engine_state: &mut nu_protocol::engine::EngineState) {
+    let libraries = registered_libraries()
+        .lock()
+        .expect("third-party library registry lock poisoned")
+        .remove(&engine_state_key(engine_state))
+        .unwrap_or_default();
+
+    for (name, files, prelude) in libraries {
+        if let Err(err) = load_third_party_library(engine_state, &name, &files, &prelude) {
+            eprintln!("warning: failed to load third-party library `{name}`: {err}");
+        }
+    }
+}
+
+/// Read `$env.LC_MESSAGES`, falling back to `$env.LANG`, and normalize it to a bare locale
+/// tag: `"fr_FR.UTF-8"` becomes `"fr_FR"`, further falling back to just the language
+/// (`"fr"`) if we don't have a catalog for the full tag.
+fn requested_locale_tags(engine_state: &nu_protocol::engine::EngineState) -> Vec<String> {
+    let raw = engine_state
+        .get_env_var("LC_MESSAGES")
+        .or_else(|| engine_state.get_env_var("LANG"))
+        .and_then(|v| v.as_str().ok().map(str::to_string));
+
+    let Some(raw) = raw else {
+        return vec![];
+    };
+
+    // Strip the `.<encoding>` and `@<modifier>` suffixes POSIX locale names can carry.
+    let tag = raw
+        .split(['.', '@'])
+        .next()
+        .unwrap_or(raw.as_str())
+        .to_string();
+
+    match tag.split_once('_') {
+        Some((language, _)) => vec![tag.clone(), language.to_string()],
+        None => vec![tag],
+    }
+}
+
+/// Pick the best available message catalog for the current `$env.LC_MESSAGES`/`$env.LANG`,
+/// falling back to [`DEFAULT_LOCALE`] when nothing matches.
+fn select_locale_catalog(engine_state: &nu_protocol::engine::EngineState) -> &'static str {
+    requested_locale_tags(engine_state)
+        .iter()
+        .find_map(|tag| {
+            LOCALE_CATALOGS
+                .iter()
+                .find(|(locale, _)| locale == tag)
+                .map(|(_, content)| *content)
+        })
+        .unwrap_or_else(|| {
+            LOCALE_CATALOGS
+                .iter()
+                .find(|(locale, _)| *locale == DEFAULT_LOCALE)
+                .map(|(_, content)| *content)
+                .expect("DEFAULT_LOCALE must have a catalog in LOCALE_CATALOGS")
+        })
+}
+
 pub fn load_standard_library(
     engine_state: &mut nu_protocol::engine::EngineState,
 ) -> Result<(), miette::ErrReport> {
@@ -16,26 +460,30 @@ pub fn load_standard_library(
         // in their working directory.
         let std_dir = PathBuf::from(NU_STDLIB_VIRTUAL_DIR).join("std");
 
-        // these modules are loaded in the order they appear in this list
-        #[rustfmt::skip]
-        let submodules = vec![
-            // helper modules that could be used in other parts of the library
-            ("log", include_str!("../lib/log.nu")),
-
-            // the rest of the library
-            ("mod.nu", include_str!("../std/mod.nu")),
-            ("assert.nu", include_str!("../std/assert.nu")),
-            ("input.nu", include_str!("../std/input.nu")),
-            ("dirs", include_str!("../std/dirs.nu")), // moved from lib to std
-            // FIXME: the files above are in ../std/*.nu
-            //        the ones below are in ../lib/*.nu
-            //        Is this expected?
-            ("iter", include_str!("../lib/iter.nu")),
-            ("help", include_str!("../lib/help.nu")),
-            ("testing", include_str!("../lib/testing.nu")),
-            ("xml", include_str!("../lib/xml.nu")),
-            ("dt", include_str!("../lib/dt.nu")),
-        ];
+        // By default every submodule is parsed here, same as before lazy loading existed: this
+        // crate has no command resolver of its own to hook `load_lazy_stdlib_module` into, so
+        // treating `LAZY_STD_MODULES` as deferred by default would make `std assert`, `std
+        // iter`, etc. silently vanish for every embedder. Set `NU_STDLIB_LAZY=1` to defer them
+        // instead, but only if whatever resolves commands for this `EngineState` also calls
+        // `lazy_stdlib_module_for_command`/`load_lazy_stdlib_module` on a miss.
+        //
+        // `i18n.nu` is picked per-call from `LOCALE_CATALOGS` (it depends on the current
+        // `$env.LC_MESSAGES`/`$env.LANG`, so it can't live in a `'static` module table) and
+        // folded into the same `std` directory as everything else below, so it comes out as
+        // `std i18n` -- a real, addressable submodule -- rather than a top-level module of its
+        // own. Wiring `std assert`/`std help` to actually `use std i18n` for their own
+        // user-facing strings is left to a future change to those files; this change only adds
+        // the catalog itself and makes it reachable.
+        let mut submodules: Vec<(&str, &str)> = if stdlib_lazy_mode() {
+            EAGER_STD_MODULES.to_vec()
+        } else {
+            EAGER_STD_MODULES
+                .iter()
+                .chain(LAZY_STD_MODULES)
+                .copied()
+                .collect()
+        };
+        submodules.push(("i18n.nu", select_locale_catalog(engine_state)));
 
         // Define commands to be preloaded into the default (top level, unprefixed) namespace.
         // User can invoke these without having to `use std` beforehand.
@@ -65,7 +513,7 @@ pub fn load_standard_library(
         let mut working_set = StateWorkingSet::new(engine_state);
         let mut std_virt_paths = vec![];
 
-        for (name, content) in std_files.drain(..) {
+        for (name, content) in submodules {
             let name = std_dir.join(name);
 
             let file_id =
@@ -78,6 +526,7 @@ pub fn load_standard_library(
         }
 
         let std_dir = std_dir.to_string_lossy().to_string();
+
         let source = format!(
             r#"
 # Define the `std` module
@@ -136,5 +585,150 @@ use std pwd
     let cwd = current_dir(engine_state, &stack)?;
     engine_state.merge_env(&mut stack, cwd)?;
 
+    load_extra_prelude(engine_state)?;
+
+    load_registered_libraries(engine_state);
+
+    Ok(())
+}
+
+/// Apply `$env.config.std_prelude` (on top of the built-in prelude already merged above), e.g.:
+///   $env.config.std_prelude = [{ alias: "ll", target: "dirs" }]
+///
+/// Runs after `std` itself is merged, so each entry's `target` can be checked against the
+/// commands `std` actually exports right now via `find_decl`, rather than just checking that
+/// its first path segment names a submodule. That also means the warning for a bad entry is
+/// accurate: a target naming a real but not-currently-loaded module (e.g. `iter` while
+/// `NU_STDLIB_LAZY=1` is deferring it) is reported as not loaded, not as nonexistent.
+///
+/// Only single-segment targets (e.g. `dirs`, not `help commands`) are supported: `use std
+/// <target>` only brings the *last* segment of a multi-word target into scope unprefixed, so
+/// `alias <alias> = <target>` would reference a name that was never actually imported. A
+/// multi-word target is rejected with the same warning as an unknown one rather than silently
+/// producing a broken alias.
+fn load_extra_prelude(
+    engine_state: &mut nu_protocol::engine::EngineState,
+) -> Result<(), miette::ErrReport> {
+    let mut uses = String::new();
+    for entry in config_prelude_entries(engine_state) {
+        if entry.target.split_whitespace().count() > 1 {
+            eprintln!(
+                "warning: ignoring $env.config.std_prelude entry `{}`: \
+                 target `{}` names a subcommand, but std_prelude only supports \
+                 single-segment targets",
+                entry.alias, entry.target
+            );
+            continue;
+        }
+
+        let full_target = format!("std {}", entry.target);
+        if engine_state.find_decl(full_target.as_bytes(), &[]).is_some() {
+            uses.push_str(&format!(
+                "use {full_target}\nalias {} = {}\n",
+                entry.alias, entry.target
+            ));
+        } else if is_known_prelude_target(&entry.target) {
+            eprintln!(
+                "warning: ignoring $env.config.std_prelude entry `{}`: \
+                 std module `{}` exists but isn't loaded in this session",
+                entry.alias, entry.target
+            );
+        } else {
+            eprintln!(
+                "warning: ignoring $env.config.std_prelude entry `{}`: \
+                 no such std command `{}`",
+                entry.alias, entry.target
+            );
+        }
+    }
+
+    if uses.is_empty() {
+        return Ok(());
+    }
+
+    let (block, delta) = {
+        let mut working_set = StateWorkingSet::new(engine_state);
+        let block = parse(
+            &mut working_set,
+            Some("loading stdlib std_prelude"),
+            uses.as_bytes(),
+            false,
+        );
+
+        if let Some(err) = working_set.parse_errors.first() {
+            report_error(&working_set, err);
+        }
+
+        (block, working_set.render())
+    };
+
+    engine_state.merge_delta(delta)?;
+
+    let mut stack = Stack::new();
+    eval_block(
+        engine_state,
+        &mut stack,
+        &block,
+        PipelineData::Empty,
+        false,
+        false,
+    )?;
+
+    let cwd = current_dir(engine_state, &stack)?;
+    engine_state.merge_env(&mut stack, cwd)?;
+
     Ok(())
 }
+
+#[cfg(test)]
+mod lazy_loading_tests {
+    use super::*;
+    use nu_protocol::engine::EngineState;
+
+    // `load_standard_library` itself always loads everything (see `stdlib_lazy_mode`), since
+    // nothing in this crate hooks the lazy path into command resolution. This test exercises
+    // `load_lazy_stdlib_module` directly, the way a resolver wired up for `NU_STDLIB_LAZY=1`
+    // would: it maps a command name to its lazy module via `lazy_stdlib_module_for_command`,
+    // then checks that the first load into a cold `EngineState` (one that never called
+    // `load_standard_library`) reports doing the work and a second load is a no-op. It does not
+    // check that the command resolves afterwards.
+    #[test]
+    fn lazy_module_loads_on_cold_start_and_is_idempotent() {
+        let mut engine_state = EngineState::new();
+
+        assert_eq!(lazy_stdlib_module_for_command("assert equal"), Some("assert"));
+        assert_eq!(lazy_stdlib_module_for_command("nonexistent thing"), None);
+
+        let loaded = load_lazy_stdlib_module(&mut engine_state, "assert")
+            .expect("cold-start load of a known lazy module must succeed");
+        assert!(loaded, "first load of `assert` should report that it did the work");
+
+        let loaded_again = load_lazy_stdlib_module(&mut engine_state, "assert")
+            .expect("re-requesting an already-loaded module must still succeed");
+        assert!(
+            !loaded_again,
+            "loading `assert` a second time should be a no-op, not a re-merge"
+        );
+    }
+
+    // The loaded-set is keyed by `engine_state_key`, not just the module name: loading a module
+    // into one `EngineState` must not make it look already-loaded for a second, independent
+    // `EngineState` (e.g. two engines in the same test binary, or one recreated mid-process).
+    #[test]
+    fn lazy_module_load_is_scoped_per_engine_state() {
+        let mut engine_a = EngineState::new();
+        let mut engine_b = EngineState::new();
+
+        let loaded_a = load_lazy_stdlib_module(&mut engine_a, "assert")
+            .expect("cold-start load into engine A must succeed");
+        assert!(loaded_a);
+
+        let loaded_b = load_lazy_stdlib_module(&mut engine_b, "assert")
+            .expect("cold-start load into engine B must succeed independently of engine A");
+        assert!(
+            loaded_b,
+            "engine B must load `assert` on its own merits, not see it as already-loaded \
+             because engine A loaded it"
+        );
+    }
+}